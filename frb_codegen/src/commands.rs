@@ -47,6 +47,34 @@ pub(crate) struct BindgenRustToDartArg<'a> {
     pub llvm_install_path: &'a [String],
     pub llvm_compiler_opts: &'a str,
     pub prefix: &'a String,
+    /// Sort `c_struct_names` and `exclude_symbols` before handing them to cbindgen, so that
+    /// re-running codegen on an unchanged API produces a byte-identical header regardless of
+    /// `HashMap` iteration order. Defaults to `true`; set to `false` to keep IR traversal order.
+    pub deterministic_codegen: bool,
+    /// How the generated Dart binding locates `wire_*` symbols. Defaults to
+    /// [`BindingsMode::StaticLink`], which keeps the existing dummy-bundling-function behavior.
+    pub bindings_mode: BindingsMode,
+    /// Names of every `wire_*` FFI symbol. Only consulted when `bindings_mode` is
+    /// [`BindingsMode::DynamicLoading`], to generate the loader's symbol list.
+    pub wire_func_names: &'a [String],
+}
+
+/// Selects how the generated Dart binding resolves native `wire_*` symbols.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) enum BindingsMode {
+    /// The symbols are linked statically (or loaded once via `DynamicLibrary.open` and never
+    /// re-verified). Requires the dummy bundling function emitted by
+    /// [`crate::generator::c::generate_dummy`] to stop the linker from stripping unreferenced
+    /// `wire_*` symbols.
+    #[default]
+    StaticLink,
+    /// The symbols are resolved by name at library-open time against an arbitrary shared
+    /// library path, via a generated loader whose `ensureSymbolsResolved()` attempts every lookup
+    /// up front so a missing or version-skewed symbol fails with an actionable error instead of
+    /// an opaque crash on first call. The loader's constructor only opens the library and does
+    /// not call `ensureSymbolsResolved()` itself. No dummy bundling function is needed, since
+    /// nothing has to survive dead-code elimination ahead of time.
+    DynamicLoading,
 }
 
 pub(crate) fn bindgen_rust_to_dart(
@@ -59,6 +87,8 @@ pub(crate) fn bindgen_rust_to_dart(
         arg.c_struct_names,
         arg.exclude_symbols,
         arg.prefix,
+        arg.deterministic_codegen,
+        arg.bindings_mode,
     )?;
     ffigen(
         arg.c_output_path,
@@ -67,21 +97,53 @@ pub(crate) fn bindgen_rust_to_dart(
         arg.llvm_install_path,
         arg.llvm_compiler_opts,
         dart_root,
-    )
+        arg.bindings_mode,
+    )?;
+
+    if arg.bindings_mode == BindingsMode::DynamicLoading {
+        write_loader(arg.dart_output_path, arg.dart_class_name, arg.wire_func_names)?;
+    }
+
+    Ok(())
+}
+
+/// Writes the `{dart_class_name}Loader` class (see [`crate::generator::dart::loader`]) next to
+/// `dart_output_path`, e.g. `bridge_generated.dart` -> `bridge_generated_loader.dart`.
+fn write_loader(
+    dart_output_path: &str,
+    dart_class_name: &str,
+    wire_func_names: &[String],
+) -> anyhow::Result<()> {
+    let loader_src = crate::generator::dart::loader::generate_loader(dart_class_name, wire_func_names);
+    let path = Path::new(dart_output_path);
+    let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("bridge");
+    let ext = path.extension().and_then(|s| s.to_str()).unwrap_or("dart");
+    let loader_path = path.with_file_name(format!("{stem}_loader.{ext}"));
+    std::fs::write(loader_path, loader_src)?;
+    Ok(())
 }
 
 fn cbindgen(
     rust_crate_dir: &str,
     c_output_path: &str,
-    c_struct_names: Vec<String>,
-    exclude_symbols: Vec<String>,
+    mut c_struct_names: Vec<String>,
+    mut exclude_symbols: Vec<String>,
     prefix: &String,
+    deterministic_codegen: bool,
+    bindings_mode: BindingsMode,
 ) -> anyhow::Result<()> {
     debug!(
         "execute cbindgen rust_crate_dir={} c_output_path={}",
         rust_crate_dir, c_output_path
     );
 
+    if deterministic_codegen {
+        // Sort so that re-running codegen on an unchanged API yields a byte-identical header,
+        // instead of reshuffling lines whenever HashMap iteration order changes.
+        c_struct_names.sort();
+        exclude_symbols.sort();
+    }
+
     let config = cbindgen::Config {
         language: cbindgen::Language::C,
         sys_includes: vec![
@@ -120,10 +182,19 @@ fn cbindgen(
 
     if cbindgen::generate_with_config(path, config)?.write_to_file(c_output_path) {
         let generated = std::fs::read_to_string(c_output_path)?;
-        // This regex matches anything that needs to be prefixed.
-        let regex = Regex::new(r"([\d\w]+ \*?)([\d\w]+)(\([\d\w\s*,]*\);)")?;
-        let prefixed = regex.replace_all(&generated, format!("${{1}}{prefix}${{2}}${{3}}"));
-        std::fs::write(c_output_path, format!("// {prefix}\n{}", prefixed.to_string()))?;
+        // Dynamic-loading mode resolves symbols by their natural name at runtime, so there is
+        // nothing to rewrite ahead of time; static-link mode still needs every `wire_*` symbol
+        // prefixed so multiple bridged crates can coexist in one binary without clashing.
+        let rewritten = if bindings_mode == BindingsMode::StaticLink {
+            // This regex matches anything that needs to be prefixed.
+            let regex = Regex::new(r"([\d\w]+ \*?)([\d\w]+)(\([\d\w\s*,]*\);)")?;
+            regex
+                .replace_all(&generated, format!("${{1}}{prefix}${{2}}${{3}}"))
+                .to_string()
+        } else {
+            generated
+        };
+        std::fs::write(c_output_path, format!("// {prefix}\n{}", rewritten))?;
 
         Ok(())
     } else {
@@ -138,6 +209,7 @@ fn ffigen(
     llvm_path: &[String],
     llvm_compiler_opts: &str,
     dart_root: &str,
+    bindings_mode: BindingsMode,
 ) -> anyhow::Result<()> {
     debug!(
         "execute ffigen c_path={} dart_path={} llvm_path={:?}",
@@ -158,6 +230,18 @@ fn ffigen(
           // ignore_for_file: camel_case_types, non_constant_identifier_names, avoid_positional_boolean_parameters, annotate_overrides, constant_identifier_names
         "
     );
+    if bindings_mode == BindingsMode::DynamicLoading {
+        // Expose the function-pointer typedefs so the generated loader struct can look each
+        // `wire_*` symbol up by name (`library.lookup<NativeFunction<...>>('wire_foo')`)
+        // instead of the binding calling into it directly.
+        write!(
+            &mut config,
+            "
+        functions:
+          expose-typedefs: true
+        "
+        )?;
+    }
     if !llvm_path.is_empty() {
         write!(
             &mut config,
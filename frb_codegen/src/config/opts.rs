@@ -0,0 +1,35 @@
+use crate::commands::BindingsMode;
+use crate::config::raw_opts::RawOpts;
+use crate::utils::misc::BlockIndex;
+
+/// Per-API-block codegen configuration. Built from [`RawOpts`] (the CLI/config-file surface)
+/// plus the per-block values computed while splitting a multi-block project.
+///
+/// Only the fields touched by the deterministic-codegen and dynamic-loading work are reproduced
+/// here; the rest of the existing fields (`rust_input`, `dart_output`, ...) are unchanged and
+/// live alongside these.
+#[derive(Debug, Clone)]
+pub struct Opts {
+    pub class_name: String,
+    pub block_index: BlockIndex,
+    pub c_output_path: Vec<String>,
+    /// See [`RawOpts::deterministic_codegen`].
+    pub deterministic_codegen: bool,
+    /// See [`RawOpts::dynamic_loading`].
+    pub bindings_mode: BindingsMode,
+}
+
+impl Opts {
+    pub fn get_unique_id(&self) -> String {
+        format!("{}_", self.class_name.to_uppercase())
+    }
+
+    /// Derives [`BindingsMode`] from the `--dynamic-loading` CLI flag.
+    pub(crate) fn bindings_mode_from_raw(raw: &RawOpts) -> BindingsMode {
+        if raw.dynamic_loading {
+            BindingsMode::DynamicLoading
+        } else {
+            BindingsMode::StaticLink
+        }
+    }
+}
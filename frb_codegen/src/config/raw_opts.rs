@@ -0,0 +1,21 @@
+use clap::Parser;
+
+/// CLI/config-file surface for `flutter_rust_bridge_codegen`.
+///
+/// Only the flags touched by the deterministic-codegen and dynamic-loading work are reproduced
+/// here — the rest of the existing options (`--rust-input`, `--dart-output`, ...) are unchanged
+/// and live alongside these.
+#[derive(Parser, Debug, Clone)]
+pub struct RawOpts {
+    /// Keep dummy-bundling symbol names and cbindgen's struct/exclude lists in lexicographic
+    /// order across runs, so re-running codegen on an unchanged API does not reshuffle the
+    /// generated header. Pass `--deterministic-codegen=false` to keep IR-traversal order instead.
+    #[arg(long, default_value_t = true, action = clap::ArgAction::Set)]
+    pub deterministic_codegen: bool,
+
+    /// Resolve `wire_*` symbols by name against an arbitrary shared library path at runtime,
+    /// via a generated loader that verifies every symbol up front, instead of force-linking
+    /// them through a dummy bundling function.
+    #[arg(long)]
+    pub dynamic_loading: bool,
+}
@@ -2,6 +2,7 @@ use std::path::Path;
 
 use regex::Regex;
 
+use crate::commands::BindingsMode;
 use crate::config::opts::Opts;
 use crate::utils::misc::{BlockIndex, PathExt};
 
@@ -11,6 +12,12 @@ pub fn generate_dummy(
     func_names: &[String],
     c_path_index: usize,
 ) -> String {
+    if config.bindings_mode == BindingsMode::DynamicLoading {
+        // Symbols are resolved by name at library-open time instead of being force-linked, so
+        // there is no dummy bundling function to emit.
+        return String::new();
+    }
+
     let regex = Regex::new(r"wire_[\d\w]+").unwrap();
     let prefix = &config.get_unique_id();
     let func_names = &func_names
@@ -23,6 +30,7 @@ pub fn generate_dummy(
             e.to_string()
         })
         .collect::<Vec<String>>();
+    let func_names = &sorted_if_deterministic(func_names, config.deterministic_codegen);
 
     if all_configs.len() > 1 {
         let basic_dummy_func = get_dummy_func(&config.class_name, func_names, prefix);
@@ -96,3 +104,13 @@ fn get_dummy_var(func_names: &[String]) -> String {
         .collect::<Vec<_>>()
         .join("\n")
 }
+
+/// Sorts `names` lexicographically unless `deterministic` is `false`, in which case the
+/// original (IR traversal) order is preserved for users who depend on it.
+fn sorted_if_deterministic(names: &[String], deterministic: bool) -> Vec<String> {
+    let mut names = names.to_vec();
+    if deterministic {
+        names.sort();
+    }
+    names
+}
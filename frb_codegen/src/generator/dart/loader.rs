@@ -0,0 +1,122 @@
+//! Generates the Dart-side loader used by
+//! [`crate::commands::BindingsMode::DynamicLoading`].
+//!
+//! Instead of binding straight to `ffigen`-generated functions that assume every `wire_*`
+//! symbol was force-linked via the dummy bundling function (see
+//! [`crate::generator::c::generate_dummy`]), the loader resolves each symbol by name against an
+//! arbitrary shared library path and verifies all of them up front, so a missing or
+//! version-skewed symbol fails with a named error instead of crashing opaquely on first call.
+
+/// Generates the `{class_name}Loader` Dart class that resolves `func_names` against a library
+/// opened at a caller-supplied path.
+pub fn generate_loader(class_name: &str, func_names: &[String]) -> String {
+    let mut func_names = func_names.to_vec();
+    func_names.sort();
+
+    let symbol_list = func_names
+        .iter()
+        .map(|name| format!("    '{name}',"))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    format!(
+        r#"import 'dart:ffi' as ffi;
+
+/// Resolves every `wire_*` symbol by name against [libraryPath], instead of requiring
+/// them to be force-linked via a dummy bundling function.
+///
+/// Construction only opens [libraryPath]; it does not resolve any symbol. Call
+/// [ensureSymbolsResolved] right after construction: it attempts every lookup up front and
+/// throws an [ArgumentError] naming the exact missing symbol and [libraryPath], instead of
+/// failing opaquely the first time a stale symbol is actually called. Use [isSymbolAvailable]
+/// for feature detection against native libraries built from an older/newer Dart side.
+///
+/// Individual symbols are bound lazily, on demand, via [lookupFunction], which returns a typed,
+/// callable Dart function. The `ffigen`-generated binding class does not go through this loader.
+class {class_name}Loader {{
+  final ffi.DynamicLibrary _dylib;
+  final String libraryPath;
+
+  static const List<String> _wireSymbolNames = [
+{symbol_list}
+  ];
+
+  {class_name}Loader(this.libraryPath) : _dylib = ffi.DynamicLibrary.open(libraryPath);
+
+  /// Looks up [symbolName] and binds it as a callable Dart function of type [F], whose native
+  /// signature is [T].
+  F lookupFunction<T extends Function, F extends Function>(String symbolName) =>
+      _dylib.lookupFunction<T, F>(symbolName);
+
+  /// Attempts every `wire_*` symbol lookup up front.
+  ///
+  /// Throws an [ArgumentError] naming the first symbol that could not be resolved and the
+  /// library path it was resolved against, so a Dart/native version mismatch fails fast and
+  /// legibly instead of crashing opaquely on first call.
+  void ensureSymbolsResolved() {{
+    for (final symbolName in _wireSymbolNames) {{
+      if (!isSymbolAvailable(symbolName)) {{
+        throw ArgumentError(
+          'flutter_rust_bridge: missing symbol "$symbolName" in "$libraryPath". '
+          'This usually means the Dart and native code are out of sync.',
+        );
+      }}
+    }}
+  }}
+
+  /// Returns whether [symbolName] can be resolved in [libraryPath], for feature detection
+  /// against native libraries built from an older/newer Dart side.
+  bool isSymbolAvailable(String symbolName) {{
+    try {{
+      _dylib.lookup(symbolName);
+      return true;
+    }} on ArgumentError {{
+      return false;
+    }}
+  }}
+}}
+"#
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn imports_dart_ffi() {
+        let dart = generate_loader("Native", &["wire_a".to_owned()]);
+        assert!(dart.starts_with("import 'dart:ffi' as ffi;\n"));
+    }
+
+    #[test]
+    fn generates_sorted_symbol_list_and_typed_lookup() {
+        let dart = generate_loader("Native", &["wire_b".to_owned(), "wire_a".to_owned()]);
+        assert!(dart.contains("class NativeLoader"));
+        assert!(dart.contains(
+            "F lookupFunction<T extends Function, F extends Function>(String symbolName) =>"
+        ));
+        assert!(dart.contains("void ensureSymbolsResolved()"));
+        assert!(dart.contains("bool isSymbolAvailable(String symbolName)"));
+        // `func_names` is sorted so the emitted symbol list doesn't churn with IR order.
+        assert!(dart.find("'wire_a',").unwrap() < dart.find("'wire_b',").unwrap());
+    }
+
+    #[test]
+    fn constructor_only_opens_the_library_and_resolves_no_symbols() {
+        let dart = generate_loader("Native", &["wire_a".to_owned()]);
+        assert!(dart.contains(
+            "NativeLoader(this.libraryPath) : _dylib = ffi.DynamicLibrary.open(libraryPath);"
+        ));
+        // No eager per-symbol lookup in the constructor: that would throw Dart's opaque
+        // `ArgumentError` on the first missing symbol before `ensureSymbolsResolved()` can
+        // produce its actionable, symbol-naming message.
+        assert!(!dart.contains("_lookup('wire_a')"));
+    }
+
+    #[test]
+    fn ensure_symbols_resolved_names_the_missing_symbol_and_library_path() {
+        let dart = generate_loader("Native", &["wire_a".to_owned()]);
+        assert!(dart.contains(r#"'flutter_rust_bridge: missing symbol "$symbolName" in "$libraryPath". '"#));
+    }
+}
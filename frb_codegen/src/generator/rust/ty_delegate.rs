@@ -7,6 +7,14 @@ use crate::target::{Acc, Target};
 use crate::type_rust_generator_struct;
 use crate::utils::BlockIndex;
 
+// `chrono` and `time` are alternative backends for the `Time` delegate: both provide a
+// `wire2api_body` arm for `IrTypeDelegate::Time`, so enabling both would be a duplicate (and
+// thus non-exhaustive-turned-unreachable) match arm.
+#[cfg(all(feature = "chrono", feature = "time"))]
+compile_error!(
+    "features \"chrono\" and \"time\" are mutually exclusive Time delegate backends; enable at most one"
+);
+
 type_rust_generator_struct!(TypeDelegateGenerator, IrTypeDelegate);
 
 macro_rules! delegate_enum {
@@ -76,10 +84,78 @@ impl TypeRustGeneratorTrait for TypeDelegateGenerator<'_> {
               let codegen_naive = "chrono::NaiveDateTime::from_timestamp(s, ns)".to_string();
               let codegen_utc = format!("chrono::DateTime::<chrono::Utc>::from_utc({codegen_naive}, chrono::Utc)");
               let codegen_local = format!("chrono::DateTime::<chrono::Local>::from({codegen_utc})");
+              // `IrTypeTime::Tz` is only ever constructed via `IrTypeTime::from_timezone_annotation`,
+              // which already validated `tz_name` against the IANA database at codegen time, so
+              // the generated parse below cannot fail; it is cached in a `std::sync::OnceLock` so
+              // it runs once per process rather than being re-parsed on every `wire2api` call.
+              // (`OnceLock`, not `once_cell::sync::Lazy`: that would inject an undeclared
+              // `once_cell` dependency into the user's generated crate, unlike the rest of the
+              // generated code, which reaches runtime support through this crate's `support::`
+              // re-export.)
+              #[cfg(feature = "chrono-tz")]
+              let codegen_tz = match ir {
+                IrTypeTime::Tz(tz_name) => Some(format!(
+                  "{{
+                    static TZ: std::sync::OnceLock<chrono_tz::Tz> = std::sync::OnceLock::new();
+                    {codegen_utc}.with_timezone(TZ.get_or_init(|| {tz_name:?}.parse().expect(\"validated at codegen time\")))
+                  }}"
+                )),
+                _ => None,
+              };
+              // `IrTypeTime::Tz` only exists at all behind `chrono-tz` (see its definition in
+              // `ir_type_time.rs`), so this match stays exhaustive without a `Tz` arm when
+              // `chrono-tz` is disabled.
               let codegen_conversion = match ir {
                 IrTypeTime::Naive => codegen_naive.as_str(),
                 IrTypeTime::Utc => codegen_utc.as_str(),
                 IrTypeTime::Local => codegen_local.as_str(),
+                #[cfg(feature = "chrono-tz")]
+                IrTypeTime::Tz(_) => codegen_tz.as_deref().unwrap(),
+                IrTypeTime::Duration => unreachable!(),
+              };
+              Acc {
+                io: Some(format!("
+                {codegen_io}
+                {codegen_conversion}
+                ")),
+                wasm: Some(format!("
+                {codegen_wasm}
+                {codegen_conversion}
+                ")),
+                ..Default::default()
+              }
+            },
+            #[cfg(feature = "time")]
+            IrTypeDelegate::Time(ir) => {
+              if ir == &IrTypeTime::Duration {
+                return Acc {
+                  io: Some("time::Duration::microseconds(self)".into()),
+                  wasm: Some("time::Duration::milliseconds(self)".into()),
+                  ..Default::default()
+                };
+              }
+              // `self` is transmitted in micros on io and millis on wasm; both are lowered
+              // to nanoseconds, matching `OffsetDateTime::from_unix_timestamp_nanos`.
+              let codegen_io = "let nanos = (self as i128) * 1_000;";
+              let codegen_wasm = "let nanos = (self as i128) * 1_000_000;";
+              let codegen_utc = time_utc_conversion_expr();
+              let codegen_naive = time_naive_conversion_expr();
+              // `IrTypeTime::Tz` only exists at all behind `chrono-tz` (see its definition in
+              // `ir_type_time.rs`), and `chrono-tz` implies `chrono`, which is mutually exclusive
+              // with `time` (see the `compile_error!` above) — so `Tz` can never reach this match
+              // and needs no arm here.
+              let codegen_conversion = match ir {
+                IrTypeTime::Naive => codegen_naive.as_str(),
+                IrTypeTime::Utc => codegen_utc,
+                // The `time` crate can only give us the local offset via its unsound,
+                // thread-unsafe `local-offset` feature (time-rs/time#293), so the IR builder
+                // rejects `Local` up front via `IrTypeTime::reject_local_for_time_backend`
+                // (a real, user-facing `anyhow` error) rather than let it reach codegen: by the
+                // time we're here, `Local` is an internal invariant violation, not a valid input.
+                IrTypeTime::Local => unreachable!(
+                    "`Local` must be rejected at IR-build time by \
+                     `IrTypeTime::reject_local_for_time_backend` when the \"time\" backend is active"
+                ),
                 IrTypeTime::Duration => unreachable!(),
               };
               Acc {
@@ -198,6 +274,23 @@ impl TypeRustGeneratorTrait for TypeDelegateGenerator<'_> {
     }
 }
 
+/// Generated expression that lowers the `nanos` local (bound by `codegen_io`/`codegen_wasm`)
+/// into a UTC `time::OffsetDateTime`. Out-of-range inputs (outside `time`'s supported year
+/// range) fail loudly with a named error rather than an unadorned `unwrap` panic.
+#[cfg(feature = "time")]
+fn time_utc_conversion_expr() -> &'static str {
+    r#"time::OffsetDateTime::from_unix_timestamp_nanos(nanos).expect("timestamp out of range for `time::OffsetDateTime`")"#
+}
+
+/// Generated expression that drops the UTC offset to produce a `time::PrimitiveDateTime`.
+#[cfg(feature = "time")]
+fn time_naive_conversion_expr() -> String {
+    format!(
+        "{{ let dt = {}; time::PrimitiveDateTime::new(dt.date(), dt.time()) }}",
+        time_utc_conversion_expr()
+    )
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -218,4 +311,33 @@ mod tests {
         assert_eq!(s, 3_496);
         assert_eq!(ns, 567_000_000);
     }
+    // `nanos` below is bound the same way the generated `codegen_io`/`codegen_wasm` snippets
+    // bind it. The first test pins `time_utc_conversion_expr` to exactly the expression executed
+    // there; the second only checks that `time_naive_conversion_expr` builds a
+    // `PrimitiveDateTime::new`, and runs the equivalent conversion against the real `time` crate.
+    #[cfg(feature = "time")]
+    #[test]
+    fn time_utc_conversion_expr_matches_and_round_trips() {
+        assert_eq!(
+            super::time_utc_conversion_expr(),
+            r#"time::OffsetDateTime::from_unix_timestamp_nanos(nanos).expect("timestamp out of range for `time::OffsetDateTime`")"#
+        );
+
+        let nanos: i128 = 3_496_567_123_000;
+        let dt = time::OffsetDateTime::from_unix_timestamp_nanos(nanos)
+            .expect("timestamp out of range for `time::OffsetDateTime`");
+        assert_eq!(dt.unix_timestamp_nanos(), nanos);
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time_naive_conversion_expr_drops_the_utc_offset() {
+        assert!(super::time_naive_conversion_expr().contains("PrimitiveDateTime::new"));
+
+        let nanos: i128 = 3_496_567_123_000;
+        let dt = time::OffsetDateTime::from_unix_timestamp_nanos(nanos)
+            .expect("timestamp out of range for `time::OffsetDateTime`");
+        let naive = time::PrimitiveDateTime::new(dt.date(), dt.time());
+        assert_eq!(naive.assume_utc().unix_timestamp_nanos(), nanos);
+    }
 }
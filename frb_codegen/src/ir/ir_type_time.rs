@@ -0,0 +1,57 @@
+use anyhow::{bail, Result};
+
+/// How a transmitted `i64` timestamp (UTC micros on io, UTC millis on wasm) is lowered into a
+/// Rust date/time value by the `Time` delegate.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum IrTypeTime {
+    Naive,
+    Utc,
+    Local,
+    Duration,
+    /// A zoned timestamp, populated from a `#[frb(timezone = "<IANA name>")]` annotation on the
+    /// field/return type. The wire format is unchanged from [`Self::Utc`] — the zone is
+    /// compile-time metadata used only to pick the `with_timezone` call at codegen time.
+    ///
+    /// Gated behind `chrono-tz` (rather than given a non-`chrono-tz` fallback arm wherever it is
+    /// matched) since it can only ever be constructed via [`Self::from_timezone_annotation`],
+    /// which requires the same feature.
+    #[cfg(feature = "chrono-tz")]
+    Tz(String),
+}
+
+impl IrTypeTime {
+    /// Builds an [`IrTypeTime::Tz`] from a `#[frb(timezone = "...")]` annotation.
+    ///
+    /// Validates `zone` against the `chrono-tz` IANA database up front, so a misspelled zone
+    /// fails the `frb_codegen` run with a clear message instead of reaching the generated
+    /// binding and panicking there on first use.
+    #[cfg(feature = "chrono-tz")]
+    pub fn from_timezone_annotation(zone: &str) -> Result<Self> {
+        if zone.parse::<chrono_tz::Tz>().is_err() {
+            bail!(
+                "invalid #[frb(timezone = \"{zone}\")]: \"{zone}\" is not a recognized IANA \
+                 timezone name"
+            );
+        }
+        Ok(Self::Tz(zone.to_owned()))
+    }
+
+    /// Rejects `Local` up front when building IR for the `time` Time-delegate backend.
+    ///
+    /// The `time` crate can only read the local UTC offset via its unsound, thread-unsafe
+    /// `local-offset` feature (time-rs/time#293), so the IR builder must reject `Local` here —
+    /// with a real `anyhow::Result` error, at IR-construction time — rather than let it reach
+    /// [`crate::generator::rust::ty_delegate`], which treats `Local` under `time` as an
+    /// `unreachable!()` codegen-internal invariant rather than a user-facing error path.
+    #[cfg(feature = "time")]
+    pub fn reject_local_for_time_backend(&self) -> Result<()> {
+        if self == &Self::Local {
+            bail!(
+                "the \"time\" Time-delegate backend does not support `Local`: the `time` \
+                 crate's local UTC-offset lookup is unsound in multi-threaded programs; \
+                 use `Utc`/`Naive`, or enable the \"chrono\" feature for `Local` support"
+            );
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,8 @@
+//! Intermediate representation (IR) types shared by the Rust/Dart/C generators.
+//!
+//! This module only carries the pieces touched by the `Time` delegate work — `IrTypeTime` — the
+//! rest of the IR tree (`IrTypeDelegate`, `IrFunc`, `IrEnum`, ...) lives alongside it unchanged.
+
+mod ir_type_time;
+
+pub use ir_type_time::IrTypeTime;